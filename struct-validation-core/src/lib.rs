@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// Represents an error that occurs during validation of a struct's field.
 ///
 /// Each `ValidationError` contains the name of the field that failed validation
@@ -58,6 +60,46 @@ impl ValidationError {
     }
 }
 
+/// Wraps a `Vec<ValidationError>` so validation failures can be returned
+/// through `?` and integrate with `anyhow`/`thiserror`-based error stacks.
+///
+/// # Examples
+///
+/// ```
+/// use struct_validation_core::{ValidationError, ValidationErrors};
+///
+/// let errors = ValidationErrors::new(vec![ValidationError::new("username", "must not be empty")]);
+/// assert_eq!(errors.to_string(), "username: must not be empty");
+/// ```
+#[derive(Debug)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl ValidationErrors {
+    /// Wraps a `Vec<ValidationError>`.
+    pub fn new(errors: Vec<ValidationError>) -> Self {
+        Self(errors)
+    }
+
+    /// Unwraps back into the plain `Vec<ValidationError>`.
+    pub fn into_vec(self) -> Vec<ValidationError> {
+        self.0
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let summary = self
+            .0
+            .iter()
+            .map(|error| format!("{}: {}", error.field, error.message))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{}", summary)
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
 /// A trait for validating structs.
 ///
 /// Implement this trait for your structs to define custom validation logic.
@@ -95,8 +137,366 @@ pub trait Validate {
     /// }
     /// ```
     fn validate(&self) -> Vec<ValidationError>;
+
+    /// Like [`validate`](Validate::validate), but returns a `Result` so
+    /// validation composes with `?` in request handlers, wrapping any
+    /// failures in [`ValidationErrors`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use struct_validation_core::{Validate, ValidationError};
+    ///
+    /// struct User {
+    ///     username: String,
+    /// }
+    ///
+    /// impl Validate for User {
+    ///     fn validate(&self) -> Vec<ValidationError> {
+    ///         if self.username.is_empty() {
+    ///             vec![ValidationError::new("username", "must not be empty")]
+    ///         } else {
+    ///             Vec::new()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// fn check(user: &User) -> Result<(), struct_validation_core::ValidationErrors> {
+    ///     user.validate_result()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(check(&User { username: String::new() }).is_err());
+    /// ```
+    fn validate_result(&self) -> Result<(), ValidationErrors> {
+        let errors = self.validate();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors::new(errors))
+        }
+    }
+
+    /// Like [`validate`](Validate::validate), but groups errors into a tree
+    /// shaped like the struct itself rather than a flat vector of dotted
+    /// field paths. Useful for consumers (e.g. JSON APIs) that want nested
+    /// output.
+    ///
+    /// The default implementation derives the tree from `validate()`'s dotted
+    /// and bracketed field paths (`"address.city"`, `"items[2].name"`), so it
+    /// works for any `Validate` impl, hand-written or derived, without extra
+    /// effort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use struct_validation_core::{Errors, Validate, ValidationError};
+    ///
+    /// struct User {
+    ///     username: String,
+    /// }
+    ///
+    /// impl Validate for User {
+    ///     fn validate(&self) -> Vec<ValidationError> {
+    ///         vec![ValidationError::new("username", "must not be empty")]
+    ///     }
+    /// }
+    ///
+    /// let user = User { username: String::new() };
+    /// match user.validate_structured() {
+    ///     Err(Errors::Object(fields)) => {
+    ///         assert!(fields.contains_key("username"));
+    ///     }
+    ///     _ => panic!("expected structured errors"),
+    /// }
+    /// ```
+    fn validate_structured(&self) -> Result<(), Errors> {
+        let errors = self.validate();
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        let mut root = HashMap::new();
+        for error in errors {
+            Errors::insert_path(&mut root, &error.field, error.message);
+        }
+        Err(Errors::Object(root))
+    }
+}
+
+/// A tree-shaped validation error, mirroring the nesting of the struct that
+/// produced it, as an alternative to the flat `Vec<ValidationError>` returned
+/// by [`Validate::validate`].
+#[derive(Debug, Clone)]
+pub enum Errors {
+    /// Errors keyed by field name, for a struct or map-like value.
+    Object(HashMap<String, Errors>),
+    /// Errors for each element of a collection, indexed by position.
+    Array(Vec<Errors>),
+    /// The messages describing why a single field failed validation.
+    Field(Vec<String>),
+}
+
+impl Errors {
+    /// Parses a dotted/bracketed field path (as produced by
+    /// [`ValidationError::add_prefix`], e.g. `"items[2].name"`) and inserts
+    /// `message` at the corresponding position in the tree rooted at `object`.
+    fn insert_path(object: &mut HashMap<String, Errors>, field: &str, message: String) {
+        match field.split_once('.') {
+            Some((head, rest)) => {
+                let (name, index) = Self::parse_segment(head);
+                let entry = object
+                    .entry(name)
+                    .or_insert_with(|| if index.is_some() { Errors::Array(Vec::new()) } else { Errors::Object(HashMap::new()) });
+
+                match index {
+                    Some(index) => {
+                        let items = Self::as_array_mut(entry);
+                        Self::ensure_len(items, index + 1);
+                        let map = Self::as_object_mut(&mut items[index]);
+                        Self::insert_path(map, rest, message);
+                    }
+                    None => Self::insert_path(Self::as_object_mut(entry), rest, message),
+                }
+            }
+            None => {
+                let (name, index) = Self::parse_segment(field);
+                let entry = object
+                    .entry(name)
+                    .or_insert_with(|| if index.is_some() { Errors::Array(Vec::new()) } else { Errors::Field(Vec::new()) });
+
+                match index {
+                    Some(index) => {
+                        let items = Self::as_array_mut(entry);
+                        Self::ensure_len(items, index + 1);
+                        Self::push_message(&mut items[index], message);
+                    }
+                    None => Self::push_message(entry, message),
+                }
+            }
+        }
+    }
+
+    /// Splits a single path segment like `"items[2]"` into its name and
+    /// optional array index.
+    fn parse_segment(segment: &str) -> (String, Option<usize>) {
+        match segment.find('[') {
+            Some(bracket) => {
+                let name = segment[..bracket].to_string();
+                let index = segment[bracket + 1..segment.len() - 1].parse::<usize>().ok();
+                (name, index)
+            }
+            None => (segment.to_string(), None),
+        }
+    }
+
+    /// Coerces `entry` into the `Array` variant, replacing it if it was
+    /// something else (which only happens if a field name collides across
+    /// validators, in which case the newest write wins).
+    fn as_array_mut(entry: &mut Errors) -> &mut Vec<Errors> {
+        if !matches!(entry, Errors::Array(_)) {
+            *entry = Errors::Array(Vec::new());
+        }
+        match entry {
+            Errors::Array(items) => items,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Coerces `entry` into the `Object` variant, as with [`Self::as_array_mut`].
+    fn as_object_mut(entry: &mut Errors) -> &mut HashMap<String, Errors> {
+        if !matches!(entry, Errors::Object(_)) {
+            *entry = Errors::Object(HashMap::new());
+        }
+        match entry {
+            Errors::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Appends `message` to `entry`'s `Field` messages, coercing it to that
+    /// variant first if needed.
+    fn push_message(entry: &mut Errors, message: String) {
+        if !matches!(entry, Errors::Field(_)) {
+            *entry = Errors::Field(Vec::new());
+        }
+        match entry {
+            Errors::Field(messages) => messages.push(message),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Pads `items` with empty `Object` placeholders so that index `len - 1`
+    /// is addressable, preserving the positions of untouched elements.
+    fn ensure_len(items: &mut Vec<Errors>, len: usize) {
+        while items.len() < len {
+            items.push(Errors::Object(HashMap::new()));
+        }
+    }
 }
 
+/// Flattens a structured [`Errors`] tree back into the same dotted/bracketed
+/// `Vec<ValidationError>` shape that [`Validate::validate`] produces.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use struct_validation_core::{Errors, ValidationError};
+///
+/// let mut object = HashMap::new();
+/// object.insert("username".to_string(), Errors::Field(vec!["must not be empty".to_string()]));
+/// let errors: Vec<ValidationError> = Errors::Object(object).into();
+///
+/// assert_eq!(errors[0].field, "username");
+/// assert_eq!(errors[0].message, "must not be empty");
+/// ```
+impl From<Errors> for Vec<ValidationError> {
+    fn from(errors: Errors) -> Self {
+        let mut out = Vec::new();
+        flatten(&mut out, None, errors);
+        out
+    }
+}
+
+fn flatten(out: &mut Vec<ValidationError>, prefix: Option<&str>, errors: Errors) {
+    match errors {
+        Errors::Field(messages) => {
+            let field = prefix.unwrap_or_default();
+            for message in messages {
+                out.push(ValidationError::new(field, &message));
+            }
+        }
+        Errors::Object(map) => {
+            for (key, value) in map {
+                let field = match prefix {
+                    Some(prefix) => format!("{}.{}", prefix, key),
+                    None => key,
+                };
+                flatten(out, Some(&field), value);
+            }
+        }
+        Errors::Array(items) => {
+            for (index, value) in items.into_iter().enumerate() {
+                let field = match prefix {
+                    Some(prefix) => format!("{}[{}]", prefix, index),
+                    None => index.to_string(),
+                };
+                flatten(out, Some(&field), value);
+            }
+        }
+    }
+}
+
+/// Serializes an [`Errors`] tree to the natural JSON shape
+/// (`{"field": {"nested": ["msg1", "msg2"]}}`), available behind the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Errors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Errors::Object(map) => serde::Serialize::serialize(map, serializer),
+            Errors::Array(items) => serde::Serialize::serialize(items, serializer),
+            Errors::Field(messages) => serde::Serialize::serialize(messages, serializer),
+        }
+    }
+}
+
+/// Normalizes the return value of a `#[validate(custom = "...")]` function into a
+/// vector of errors.
+///
+/// Custom validator functions may return whichever shape is most convenient:
+/// a single `Result<(), ValidationError>` for a simple pass/fail check, or a
+/// `Vec<ValidationError>` when a field can fail in more than one way at once.
+pub trait IntoValidationErrors {
+    /// Converts `self` into a (possibly empty) vector of `ValidationError`s.
+    fn into_validation_errors(self) -> Vec<ValidationError>;
+}
+
+impl IntoValidationErrors for Result<(), ValidationError> {
+    fn into_validation_errors(self) -> Vec<ValidationError> {
+        match self {
+            Ok(()) => Vec::new(),
+            Err(error) => vec![error],
+        }
+    }
+}
+
+impl IntoValidationErrors for Vec<ValidationError> {
+    fn into_validation_errors(self) -> Vec<ValidationError> {
+        self
+    }
+}
+
+/// A trait for structs that normalize their own fields before validation.
+///
+/// Implement this trait (or derive it with `#[derive(Modify)]`) to trim
+/// whitespace, change case, or otherwise sanitize fields in place ahead of
+/// calling [`Validate::validate`].
+///
+/// # Examples
+///
+/// ```
+/// use struct_validation_core::Modify;
+///
+/// struct User {
+///     username: String,
+/// }
+///
+/// impl Modify for User {
+///     fn modify(&mut self) {
+///         self.username = self.username.trim().to_owned();
+///     }
+/// }
+/// ```
+pub trait Modify {
+    /// Mutates `self` in place, applying each field's configured modifiers.
+    fn modify(&mut self);
+}
+
+/// Combines [`Modify`] and [`Validate`] into a single sanitize-then-check pass.
+///
+/// Blanket-implemented for every type that implements both traits, so there is
+/// nothing to derive or implement by hand.
+///
+/// # Examples
+///
+/// ```
+/// use struct_validation_core::{Modify, Validate, ValidationError, Validify};
+///
+/// struct User {
+///     username: String,
+/// }
+///
+/// impl Modify for User {
+///     fn modify(&mut self) {
+///         self.username = self.username.trim().to_owned();
+///     }
+/// }
+///
+/// impl Validate for User {
+///     fn validate(&self) -> Vec<ValidationError> {
+///         Vec::new()
+///     }
+/// }
+///
+/// let mut user = User { username: "  bob  ".to_string() };
+/// let errors = user.validate_and_modify();
+/// assert_eq!(user.username, "bob");
+/// assert!(errors.is_empty());
+/// ```
+pub trait Validify: Modify + Validate {
+    /// Applies all modifiers, then validates, returning any resulting errors.
+    fn validate_and_modify(&mut self) -> Vec<ValidationError> {
+        self.modify();
+        self.validate()
+    }
+}
+
+impl<T: Modify + Validate> Validify for T {}
+
 /// A macro to simplify validation checks.
 ///
 /// **Usage:** `validate!(vec, (boolean test expression), "field", "message")`
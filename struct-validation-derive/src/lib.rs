@@ -1,58 +1,904 @@
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Data, Fields, Error};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Attribute, Data, DeriveInput, Error, Expr, Field,
+    Fields, Meta, MetaNameValue, Path, Token, Type,
+};
 
 /// Importing `ValidationError` from the `struct_validation_core` crate.
 /// This is used to annotate validation errors with field-specific information.
 #[allow(unused_imports)]
 use struct_validation_core::ValidationError;
 
-/// Procedural macro to automatically implement the `Validate` trait for structs.
+/// Named parameters collected from a `#[validate(rule(key = value, ...))]` list,
+/// e.g. `min`, `max`, `message`.
+type RuleParams = HashMap<String, Expr>;
+
+/// Parses the `key = value` pairs inside a rule's argument list into a lookup table.
+fn parse_rule_params(list: &syn::MetaList) -> syn::Result<RuleParams> {
+    let pairs = list.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+    let mut params = RuleParams::new();
+    for pair in pairs {
+        let key = pair
+            .path
+            .get_ident()
+            .ok_or_else(|| Error::new_spanned(&pair.path, "expected a simple identifier"))?
+            .to_string();
+        params.insert(key, pair.value);
+    }
+    Ok(params)
+}
+
+/// Builds the `errors.push(..)` statement for a single condition/message pair.
+fn push_statement(field_name_str: &str, condition: TokenStream2, message: TokenStream2) -> TokenStream2 {
+    quote! {
+        if #condition {
+            errors.push(struct_validation_core::ValidationError::new(#field_name_str, &(#message)));
+        }
+    }
+}
+
+/// How a field's value is reached in generated code.
 ///
-/// This macro generates an implementation of the `Validate` trait for the annotated struct.
-/// It iterates over each named field in the struct, invokes the `validate` method on each field,
-/// prefixes any resulting `ValidationError` with the field name, and collects all errors into
-/// a single `Vec<ValidationError>`.
+/// For a struct, a field is a place expression like `self.username` or
+/// `self.0`. For an enum variant, match ergonomics bind each field as an
+/// already-borrowed `&FieldType` (e.g. `username` or `field_0`). Tracking
+/// which case we're in lets rule codegen produce a correctly-typed
+/// expression for operators (`<`, `!=`) without caring which context it's in
+/// for anything else, since method calls (`.len()`, `.contains()`, ...)
+/// resolve the same way regardless.
+struct FieldAccess {
+    expr: TokenStream2,
+    is_ref: bool,
+}
+
+impl FieldAccess {
+    /// A plain place expression of type `FieldType`, e.g. `self.username`.
+    fn place(expr: TokenStream2) -> Self {
+        Self { expr, is_ref: false }
+    }
+
+    /// An expression that already has type `&FieldType`, e.g. an
+    /// enum match-ergonomics binding.
+    fn reference(expr: TokenStream2) -> Self {
+        Self { expr, is_ref: true }
+    }
+
+    /// An expression of type `FieldType`, suitable as an operand to `<`, `!=`, etc.
+    fn value(&self) -> TokenStream2 {
+        let expr = &self.expr;
+        if self.is_ref {
+            quote! { (*#expr) }
+        } else {
+            quote! { #expr }
+        }
+    }
+
+    /// An expression of type `&FieldType`, suitable for passing to a function
+    /// expecting a reference (e.g. a `custom` validator).
+    fn reference_expr(&self) -> TokenStream2 {
+        let expr = &self.expr;
+        if self.is_ref {
+            quote! { #expr }
+        } else {
+            quote! { &#expr }
+        }
+    }
+}
+
+/// Generates the validation statement for a single `#[validate(...)]` rule.
+fn rule_statement(
+    rule: &Meta,
+    access: &FieldAccess,
+    field_name_str: &str,
+    field_ty: &Type,
+    sibling: &dyn Fn(&str) -> FieldAccess,
+    custom_ctx: CustomCtx,
+    fail_fast: bool,
+) -> syn::Result<TokenStream2> {
+    let expr = &access.expr;
+    match rule {
+        Meta::Path(path) if path.is_ident("nested") => {
+            Ok(nested_validate_statement(access, field_ty, field_name_str, fail_fast))
+        }
+        Meta::Path(path) if path.is_ident("skip") => Ok(quote! {}),
+        Meta::NameValue(nv) if nv.path.is_ident("custom") => {
+            let func_path = fn_path_from_expr(&nv.value)?;
+            let field_ref = access.reference_expr();
+            Ok(quote! {
+                errors.extend(
+                    struct_validation_core::IntoValidationErrors::into_validation_errors(#func_path(#field_ref))
+                        .into_iter()
+                        .map(|mut e| { e.add_prefix(#field_name_str); e })
+                );
+            })
+        }
+        Meta::NameValue(nv) if nv.path.is_ident("custom_with_context") => {
+            if !custom_ctx.has_context {
+                return Err(Error::new_spanned(
+                    nv,
+                    "`custom_with_context` requires the struct to declare #[validate(context = \"...\")]",
+                ));
+            }
+            if !custom_ctx.in_scope {
+                // No context is available in the plain `validate()` method; this
+                // rule only runs through `validate_with`.
+                return Ok(quote! {});
+            }
+            let func_path = fn_path_from_expr(&nv.value)?;
+            let field_ref = access.reference_expr();
+            Ok(quote! {
+                errors.extend(
+                    struct_validation_core::IntoValidationErrors::into_validation_errors(#func_path(#field_ref, ctx))
+                        .into_iter()
+                        .map(|mut e| { e.add_prefix(#field_name_str); e })
+                );
+            })
+        }
+        Meta::List(list) if list.path.is_ident("length") => {
+            let params = parse_rule_params(list)?;
+            let message_override = params.get("message");
+
+            if let Some(equal) = params.get("equal") {
+                let condition = quote! { #expr.len() != (#equal as usize) };
+                let message = message_override.map(|m| quote! { (#m).to_string() }).unwrap_or_else(|| {
+                    quote! { format!("must be exactly {} characters long", #equal) }
+                });
+                return Ok(push_statement(field_name_str, condition, message));
+            }
+
+            let min = params.get("min");
+            let max = params.get("max");
+            let condition = match (min, max) {
+                (Some(min), Some(max)) => {
+                    quote! { #expr.len() < (#min as usize) || #expr.len() > (#max as usize) }
+                }
+                (Some(min), None) => quote! { #expr.len() < (#min as usize) },
+                (None, Some(max)) => quote! { #expr.len() > (#max as usize) },
+                (None, None) => {
+                    return Err(Error::new_spanned(
+                        list,
+                        "length requires at least one of `min`, `max`, or `equal`",
+                    ))
+                }
+            };
+            let message = message_override.map(|m| quote! { (#m).to_string() }).unwrap_or_else(|| match (min, max) {
+                (Some(min), Some(max)) => quote! { format!("must be between {} and {} characters long", #min, #max) },
+                (Some(min), None) => quote! { format!("must be at least {} characters long", #min) },
+                (None, Some(max)) => quote! { format!("must be at most {} characters long", #max) },
+                (None, None) => unreachable!(),
+            });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::List(list) if list.path.is_ident("range") => {
+            let params = parse_rule_params(list)?;
+            let message_override = params.get("message");
+            let min = params.get("min");
+            let max = params.get("max");
+            let value = access.value();
+            let condition = match (min, max) {
+                (Some(min), Some(max)) => quote! { #value < #min || #value > #max },
+                (Some(min), None) => quote! { #value < #min },
+                (None, Some(max)) => quote! { #value > #max },
+                (None, None) => {
+                    return Err(Error::new_spanned(list, "range requires at least one of `min`, `max`"))
+                }
+            };
+            let message = message_override.map(|m| quote! { (#m).to_string() }).unwrap_or_else(|| match (min, max) {
+                (Some(min), Some(max)) => quote! { format!("must be between {} and {}", #min, #max) },
+                (Some(min), None) => quote! { format!("must be at least {}", #min) },
+                (None, Some(max)) => quote! { format!("must be at most {}", #max) },
+                (None, None) => unreachable!(),
+            });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::Path(path) if path.is_ident("email") => {
+            let condition = quote! { !#expr.contains('@') };
+            let message = quote! { "must be a valid email address".to_string() };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("email") => {
+            let params = parse_rule_params(list)?;
+            let condition = quote! { !#expr.contains('@') };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { "must be a valid email address".to_string() });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::Path(path) if path.is_ident("url") => {
+            let condition = quote! { !(#expr.starts_with("http://") || #expr.starts_with("https://")) };
+            let message = quote! { "must be a valid URL".to_string() };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("url") => {
+            let params = parse_rule_params(list)?;
+            let condition = quote! { !(#expr.starts_with("http://") || #expr.starts_with("https://")) };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { "must be a valid URL".to_string() });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::NameValue(nv) if nv.path.is_ident("contains") => {
+            let pattern = &nv.value;
+            let condition = quote! { !#expr.contains(#pattern) };
+            let message = quote! { format!("must contain \"{}\"", #pattern) };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("contains") => {
+            let params = parse_rule_params(list)?;
+            let pattern = params
+                .get("pattern")
+                .ok_or_else(|| Error::new_spanned(list, "contains(..) requires `pattern`"))?;
+            let condition = quote! { !#expr.contains(#pattern) };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { format!("must contain \"{}\"", #pattern) });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::NameValue(nv) if nv.path.is_ident("does_not_contain") => {
+            let pattern = &nv.value;
+            let condition = quote! { #expr.contains(#pattern) };
+            let message = quote! { format!("must not contain \"{}\"", #pattern) };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("does_not_contain") => {
+            let params = parse_rule_params(list)?;
+            let pattern = params
+                .get("pattern")
+                .ok_or_else(|| Error::new_spanned(list, "does_not_contain(..) requires `pattern`"))?;
+            let condition = quote! { #expr.contains(#pattern) };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { format!("must not contain \"{}\"", #pattern) });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::NameValue(nv) if nv.path.is_ident("must_match") => {
+            let other_name = field_name_from_expr(&nv.value)?;
+            let other = sibling(&other_name);
+            let (value, other_value) = (access.value(), other.value());
+            let condition = quote! { #value != #other_value };
+            let message = quote! { format!("must match `{}`", #other_name) };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("must_match") => {
+            let params = parse_rule_params(list)?;
+            let other_expr = params
+                .get("other")
+                .ok_or_else(|| Error::new_spanned(list, "must_match(..) requires `other`"))?;
+            let other_name = field_name_from_expr(other_expr)?;
+            let other = sibling(&other_name);
+            let (value, other_value) = (access.value(), other.value());
+            let condition = quote! { #value != #other_value };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { format!("must match `{}`", #other_name) });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        Meta::Path(path) if path.is_ident("required") => {
+            let condition = quote! { #expr.is_none() };
+            let message = quote! { "is required".to_string() };
+            Ok(push_statement(field_name_str, condition, message))
+        }
+        Meta::List(list) if list.path.is_ident("required") => {
+            let params = parse_rule_params(list)?;
+            let condition = quote! { #expr.is_none() };
+            let message = params
+                .get("message")
+                .map(|m| quote! { (#m).to_string() })
+                .unwrap_or_else(|| quote! { "is required".to_string() });
+            Ok(push_statement(field_name_str, condition, message))
+        }
+
+        other => Err(Error::new_spanned(other, "unrecognized validation rule")),
+    }
+}
+
+/// Returns the inner type of `Option<T>`, if `ty` is exactly that.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Option")
+}
+
+/// Returns the inner type of `Vec<T>`, if `ty` is exactly that.
+fn vec_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, "Vec")
+}
+
+/// Returns the single generic argument of `ty` if its last path segment is
+/// `wrapper` (e.g. `wrapper = "Option"` matches `Option<T>` and `std::option::Option<T>`).
+fn generic_inner<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else { return None };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    })
+}
+
+/// Builds the statement generated by `#[validate(nested)]`: recurses into a
+/// field's own `Validate` impl, descending through `Option<T>` (validating
+/// only when `Some`) and `Vec<T>`/arrays (validating each element and
+/// prefixing with its index, e.g. `items[3].field`).
+///
+/// For the `Vec`/array case, `fail_fast` stops the loop at the first element
+/// that produces an error, rather than validating every element — mirroring
+/// `guard_fail_fast`'s per-rule short-circuiting, but applied per-element
+/// since a single `nested` rule can otherwise hide an unbounded amount of
+/// work behind what looks like "one rule" to the caller.
+fn nested_validate_statement(access: &FieldAccess, ty: &Type, field_name_str: &str, fail_fast: bool) -> TokenStream2 {
+    let place = &access.expr;
+    if option_inner(ty).is_some() {
+        quote! {
+            if let Some(inner) = #place.as_ref() {
+                errors.extend(inner.validate().into_iter().map(|mut e| {
+                    e.add_prefix(#field_name_str);
+                    e
+                }));
+            }
+        }
+    } else if vec_inner(ty).is_some() || matches!(ty, Type::Array(_)) {
+        if fail_fast {
+            quote! {
+                for (i, item) in #place.iter().enumerate() {
+                    if !errors.is_empty() {
+                        break;
+                    }
+                    errors.extend(item.validate().into_iter().map(|mut e| {
+                        e.add_prefix(&format!("{}[{}]", #field_name_str, i));
+                        e
+                    }));
+                }
+            }
+        } else {
+            quote! {
+                for (i, item) in #place.iter().enumerate() {
+                    errors.extend(item.validate().into_iter().map(|mut e| {
+                        e.add_prefix(&format!("{}[{}]", #field_name_str, i));
+                        e
+                    }));
+                }
+            }
+        }
+    } else {
+        quote! {
+            errors.extend(#place.validate().into_iter().map(|mut e| {
+                e.add_prefix(#field_name_str);
+                e
+            }));
+        }
+    }
+}
+
+/// Builds the statement that applies a modifier to `place` (a field access
+/// expression like `self.username`), transparently descending through any
+/// `Option<T>`/`Vec<T>` wrapping via recursion, per `by_ref` tracking whether
+/// `place` is already a `&mut` reference (true once we've descended at least
+/// one level) or a plain place expression (top-level field access).
+fn modify_statement(
+    place: TokenStream2,
+    ty: &Type,
+    by_ref: bool,
+    apply: &dyn Fn(TokenStream2) -> TokenStream2,
+) -> TokenStream2 {
+    if let Some(inner_ty) = option_inner(ty) {
+        let inner = modify_statement(quote! { v }, inner_ty, true, apply);
+        quote! {
+            if let Some(v) = #place.as_mut() {
+                #inner
+            }
+        }
+    } else if let Some(inner_ty) = vec_inner(ty) {
+        let inner = modify_statement(quote! { v }, inner_ty, true, apply);
+        quote! {
+            for v in #place.iter_mut() {
+                #inner
+            }
+        }
+    } else {
+        let mutable_ref = if by_ref { quote! { #place } } else { quote! { &mut #place } };
+        apply(mutable_ref)
+    }
+}
+
+/// Generates the modifier statements for one field, from its `#[modify(...)]`
+/// attributes. A field with no such attribute is left untouched.
+fn field_modify_statements(field: &Field) -> syn::Result<Vec<TokenStream2>> {
+    let field_name = field.ident.as_ref().unwrap();
+    let mut statements = Vec::new();
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("modify") {
+            continue;
+        }
+        let rules = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for rule in &rules {
+            let apply: Box<dyn Fn(TokenStream2) -> TokenStream2> = match rule {
+                Meta::Path(path) if path.is_ident("trim") => {
+                    Box::new(|r| quote! { *#r = (#r).trim().to_owned(); })
+                }
+                Meta::Path(path) if path.is_ident("lowercase") => {
+                    Box::new(|r| quote! { *#r = (#r).to_lowercase(); })
+                }
+                Meta::Path(path) if path.is_ident("uppercase") => {
+                    Box::new(|r| quote! { *#r = (#r).to_uppercase(); })
+                }
+                Meta::Path(path) if path.is_ident("capitalize") => Box::new(|r| {
+                    quote! {
+                        *#r = {
+                            let mut chars = (#r).chars();
+                            match chars.next() {
+                                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                                None => String::new(),
+                            }
+                        };
+                    }
+                }),
+                Meta::NameValue(nv) if nv.path.is_ident("custom") => {
+                    let func_path = fn_path_from_expr(&nv.value)?;
+                    Box::new(move |r| quote! { #func_path(#r); })
+                }
+                other => return Err(Error::new_spanned(other, "unrecognized modifier")),
+            };
+
+            statements.push(modify_statement(quote! { self.#field_name }, &field.ty, false, apply.as_ref()));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// Procedural macro implementing the `Modify` trait for structs.
+///
+/// For each named field, the macro looks for a `#[modify(...)]` attribute
+/// listing one or more modifiers (`trim`, `lowercase`, `uppercase`,
+/// `capitalize`, or `custom = "path::to::fn"` where the function takes
+/// `&mut FieldType`) and applies them in place, in order, before validation
+/// would run. Modifiers transparently map over `Vec<T>` and `Option<T>`
+/// fields. Fields without a `#[modify(...)]` attribute are left untouched.
 ///
 /// # Constraints
 ///
 /// - The macro can only be derived for structs with **named fields**.
-/// - Each field in the struct must implement the `Validate` trait.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use struct_validation_core::{Validate, ValidationError, validate};
-/// use struct_validation_derive::Validate;
+/// use struct_validation_core::Modify;
+/// use struct_validation_derive::Modify;
 ///
-/// struct NonEmptyString(String);
-/// 
-/// impl Validate for NonEmptyString {
-///     fn validate(&self) -> Vec<ValidationError> {
-///         let mut errors = Vec::new();
-///         if self.0.is_empty() {
-///             errors.push(ValidationError::new("String", "must not be empty"));
-///         }
-///         errors
-///     }
+/// #[derive(Modify)]
+/// struct SignupForm {
+///     #[modify(trim, lowercase)]
+///     email: String,
 /// }
-/// impl From<String> for NonEmptyString {
-///     fn from(value: String) -> Self {
-///        Self(value)
-///     }
+///
+/// fn main() {
+///     let mut form = SignupForm { email: "  Bob@Example.com ".to_string() };
+///     form.modify();
+///     assert_eq!(form.email, "bob@example.com");
 /// }
-/// 
+/// ```
+#[proc_macro_derive(Modify, attributes(modify))]
+pub fn derive_modify(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+
+    let fields = if let Data::Struct(data) = &input.data {
+        match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Error::new_spanned(
+                    struct_name,
+                    "Modify can only be derived for structs with named fields",
+                )
+                .to_compile_error()
+                .into();
+            }
+        }
+    } else {
+        return Error::new_spanned(struct_name, "Modify can only be derived for structs")
+            .to_compile_error()
+            .into();
+    };
+
+    let field_blocks = fields.iter().map(|field| match field_modify_statements(field) {
+        Ok(statements) => quote! { #(#statements)* },
+        Err(err) => err.to_compile_error(),
+    });
+
+    let expanded = quote! {
+        impl Modify for #struct_name {
+            fn modify(&mut self) {
+                #(#field_blocks)*
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Extracts a sibling field's name from a string-literal `Expr`, as used by
+/// `must_match = "other_field"`. For tuple structs/enum tuple variants, the
+/// name is a positional index (e.g. `"0"`) instead of an identifier.
+fn field_name_from_expr(expr: &Expr) -> syn::Result<String> {
+    if let Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) = expr {
+        Ok(s.value())
+    } else {
+        Err(Error::new_spanned(expr, "expected a string literal naming another field"))
+    }
+}
+
+/// Parses a string-literal `Expr`, as used by `custom = "path::to::fn"`, into the
+/// function path it names.
+fn fn_path_from_expr(expr: &Expr) -> syn::Result<Path> {
+    if let Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) = expr {
+        s.parse()
+    } else {
+        Err(Error::new_spanned(expr, "expected a string literal naming a function"))
+    }
+}
+
+/// Controls how `#[validate(custom_with_context = "...")]` rules are expanded.
+/// Unlike `custom`, which always takes just `&FieldType` and is unaffected by
+/// this, `custom_with_context` additionally takes `&CtxType` — but only once a
+/// context is actually in scope.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CustomCtx {
+    /// The struct carries a `#[validate(context = "...")]` attribute at all.
+    /// `custom_with_context` on a struct without one is a compile error,
+    /// rather than a rule that silently never runs.
+    has_context: bool,
+    /// We're generating `validate_with`, where `ctx` is bound and can be
+    /// passed to `custom_with_context` functions. False while generating the
+    /// plain `validate()` method, where `custom_with_context` rules are
+    /// skipped (they have nothing to pass).
+    in_scope: bool,
+}
+
+/// Parses the struct-level `#[validate(context = "CtxType")]` attribute, if present.
+fn parse_struct_context(attrs: &[Attribute]) -> syn::Result<Option<Type>> {
+    for attr in attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        let rules = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for rule in &rules {
+            if let Meta::NameValue(nv) = rule {
+                if nv.path.is_ident("context") {
+                    if let Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) = &nv.value {
+                        return Ok(Some(s.parse()?));
+                    }
+                    return Err(Error::new_spanned(&nv.value, "expected a string literal naming the context type"));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Checks for the struct-level `#[validate(fail_fast)]` attribute, which
+/// requests that validation stop at the first error rather than collecting
+/// every failure.
+fn parse_struct_fail_fast(attrs: &[Attribute]) -> syn::Result<bool> {
+    for attr in attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        let rules = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for rule in &rules {
+            if let Meta::Path(path) = rule {
+                if path.is_ident("fail_fast") {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Generates the validation statements for one field, if it carries any
+/// `#[validate(...)]` attributes. Returns `None` when the field has none, so
+/// the caller can fall back to leaving it out of validation entirely.
+///
+/// `access` is how the field's value is reached (`self.username`, `self.0`,
+/// or an enum match-ergonomics binding), `field_name_str` is the error path
+/// to report it under, and `sibling` resolves another field's name (as used
+/// by `must_match`) to its own access expression in the same scope.
+fn field_validation_statements(
+    field: &Field,
+    access: FieldAccess,
+    field_name_str: &str,
+    sibling: &dyn Fn(&str) -> FieldAccess,
+    custom_ctx: CustomCtx,
+    fail_fast: bool,
+) -> syn::Result<Option<Vec<TokenStream2>>> {
+    let mut statements = Vec::new();
+    let mut saw_validate_attr = false;
+
+    for attr in &field.attrs {
+        if !attr.path().is_ident("validate") {
+            continue;
+        }
+        saw_validate_attr = true;
+        let rules = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for rule in &rules {
+            statements.push(rule_statement(rule, &access, field_name_str, &field.ty, sibling, custom_ctx, fail_fast)?);
+        }
+    }
+
+    Ok(if saw_validate_attr { Some(statements) } else { None })
+}
+
+/// Builds the `self.<field>` or `self.<index>` access expression for a
+/// sibling field named by string (as used by `must_match`), on a struct
+/// (named or tuple/newtype).
+fn self_sibling_access(name: &str) -> FieldAccess {
+    if let Ok(index) = name.parse::<usize>() {
+        let index = syn::Index::from(index);
+        FieldAccess::place(quote! { self.#index })
+    } else {
+        let ident = format_ident!("{}", name);
+        FieldAccess::place(quote! { self.#ident })
+    }
+}
+
+/// One field's worth of context needed to generate its validation
+/// statements, regardless of whether it came from a named struct field, a
+/// tuple struct field, or an enum variant's field.
+struct LogicalField<'a> {
+    field: &'a Field,
+    access: FieldAccess,
+    name: String,
+}
+
+/// Walks a struct's fields (named or tuple), pairing each with its
+/// `self.field`/`self.0`-style access expression and its error path (the
+/// field name or positional index).
+fn struct_logical_fields(fields: &Fields) -> Vec<LogicalField<'_>> {
+    match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| {
+                let ident = field.ident.as_ref().unwrap();
+                LogicalField { field, access: FieldAccess::place(quote! { self.#ident }), name: ident.to_string() }
+            })
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let index = syn::Index::from(i);
+                LogicalField { field, access: FieldAccess::place(quote! { self.#index }), name: i.to_string() }
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    }
+}
+
+/// Wraps one field's statements so that, in fail-fast mode, each individual
+/// rule only runs once every earlier rule (on this field or an earlier one)
+/// came back clean; with fail-fast off, the statements run unconditionally
+/// and every failure is collected.
+fn guard_fail_fast(statements: Vec<TokenStream2>, fail_fast: bool) -> TokenStream2 {
+    if fail_fast {
+        let guarded = statements.into_iter().map(|statement| quote! { if errors.is_empty() { #statement } });
+        quote! { #(#guarded)* }
+    } else {
+        quote! { #(#statements)* }
+    }
+}
+
+/// Generates the `errors.push`/`errors.extend` statements for every field of
+/// a struct (named or tuple).
+fn build_struct_blocks(fields: &Fields, custom_ctx: CustomCtx, fail_fast: bool) -> syn::Result<TokenStream2> {
+    let mut out = TokenStream2::new();
+    for logical in struct_logical_fields(fields) {
+        if let Some(statements) =
+            field_validation_statements(logical.field, logical.access, &logical.name, &self_sibling_access, custom_ctx, fail_fast)?
+        {
+            out.extend(guard_fail_fast(statements, fail_fast));
+        }
+    }
+    Ok(out)
+}
+
+/// Generates the `match self { ... }` body validating every variant of an
+/// enum. Each variant's fields are bound via match ergonomics (so they're
+/// already `&FieldType`), and errors are prefixed with `VariantName.field` or
+/// `VariantName.0` for tuple variants.
+fn build_enum_blocks(data_enum: &syn::DataEnum, custom_ctx: CustomCtx, fail_fast: bool) -> syn::Result<TokenStream2> {
+    let mut arms = Vec::new();
+    for variant in &data_enum.variants {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+
+        let (pattern, bindings) = match &variant.fields {
+            Fields::Named(named) => {
+                let idents: Vec<_> = named.named.iter().map(|f| f.ident.as_ref().unwrap().clone()).collect();
+                let pattern = quote! { Self::#variant_ident { #(#idents),* } };
+                let bindings = named
+                    .named
+                    .iter()
+                    .zip(idents.iter())
+                    .map(|(field, ident)| {
+                        LogicalField {
+                            field,
+                            access: FieldAccess::reference(quote! { #ident }),
+                            name: format!("{}.{}", variant_name, ident),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (pattern, bindings)
+            }
+            Fields::Unnamed(unnamed) => {
+                let binding_idents: Vec<_> =
+                    (0..unnamed.unnamed.len()).map(|i| format_ident!("field_{}", i)).collect();
+                let pattern = quote! { Self::#variant_ident(#(#binding_idents),*) };
+                let bindings = unnamed
+                    .unnamed
+                    .iter()
+                    .zip(binding_idents.iter())
+                    .enumerate()
+                    .map(|(i, (field, ident))| LogicalField {
+                        field,
+                        access: FieldAccess::reference(quote! { #ident }),
+                        name: format!("{}.{}", variant_name, i),
+                    })
+                    .collect::<Vec<_>>();
+                (pattern, bindings)
+            }
+            Fields::Unit => (quote! { Self::#variant_ident }, Vec::new()),
+        };
+
+        // Siblings for `must_match` are resolved among the variant's own
+        // bound bindings, since each variant is a distinct scope.
+        let binding_access: HashMap<String, TokenStream2> = match &variant.fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .map(|f| {
+                    let ident = f.ident.as_ref().unwrap();
+                    (ident.to_string(), quote! { #ident })
+                })
+                .collect(),
+            Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len())
+                .map(|i| {
+                    let ident = format_ident!("field_{}", i);
+                    (i.to_string(), quote! { #ident })
+                })
+                .collect(),
+            Fields::Unit => HashMap::new(),
+        };
+        let sibling = move |name: &str| -> FieldAccess {
+            match binding_access.get(name) {
+                Some(expr) => FieldAccess::reference(expr.clone()),
+                None => FieldAccess::reference(quote! { #name }),
+            }
+        };
+
+        let mut body = TokenStream2::new();
+        for logical in bindings {
+            if let Some(statements) =
+                field_validation_statements(logical.field, logical.access, &logical.name, &sibling, custom_ctx, fail_fast)?
+            {
+                body.extend(guard_fail_fast(statements, fail_fast));
+            }
+        }
+
+        arms.push(quote! { #pattern => { #body } });
+    }
+
+    Ok(quote! {
+        match self {
+            #(#arms)*
+        }
+    })
+}
+
+/// Generates the full body (everything but the surrounding `let mut errors = ...; errors`)
+/// for either `validate()` or `validate_with()`, for any supported shape: a
+/// named-field struct, a tuple/newtype struct, or an enum.
+///
+/// `fail_fast` comes from a struct-level `#[validate(fail_fast)]` attribute;
+/// when set, each rule only runs once every earlier rule came back clean.
+fn build_validate_blocks(
+    data: &Data,
+    struct_name: &syn::Ident,
+    custom_ctx: CustomCtx,
+    fail_fast: bool,
+) -> syn::Result<TokenStream2> {
+    match data {
+        Data::Struct(data_struct) => build_struct_blocks(&data_struct.fields, custom_ctx, fail_fast),
+        Data::Enum(data_enum) => build_enum_blocks(data_enum, custom_ctx, fail_fast),
+        Data::Union(_) => Err(Error::new_spanned(struct_name, "Validate cannot be derived for unions")),
+    }
+}
+
+/// Procedural macro to automatically implement the `Validate` trait for
+/// structs (named-field, tuple/newtype, or unit) and enums.
+///
+/// For each field, the macro looks for a `#[validate(...)]` attribute
+/// describing one or more rules (`length`, `range`, `email`, `url`, `contains`,
+/// `does_not_contain`, `must_match`, `required`) and emits the corresponding
+/// inline check, pushing a `ValidationError` with a sensible default message
+/// that can be overridden with `message = "..."`. A field with no
+/// `#[validate(...)]` attribute at all is not checked. Tuple/newtype struct
+/// fields are addressed by their positional index (`"0"`, `"1"`, ...).
+///
+/// For enums, each variant is matched independently and its bound fields are
+/// validated the same way; errors are prefixed with the variant name, e.g.
+/// `Paid.amount` or `Paid.0`. `must_match` resolves its `other` field among
+/// the same variant's own fields.
+///
+/// Recursing into a field's own `Validate` impl is opt-in via
+/// `#[validate(nested)]`, which descends through `Option<T>` (validating only
+/// when `Some`) and `Vec<T>`/arrays (validating each element, prefixed with
+/// its index, e.g. `items[3].field`). `#[validate(skip)]` is accepted as an
+/// explicit no-op, for documenting that a field was deliberately left out.
+///
+/// A field may also carry `#[validate(custom = "path::to::fn")]` to run
+/// arbitrary logic; the referenced function takes `&FieldType` and returns
+/// either `Result<(), ValidationError>` or `Vec<ValidationError>`. If the
+/// struct itself carries `#[validate(context = "MyCtx")]`, an additional
+/// `validate_with(&self, ctx: &MyCtx)` method is generated. A field can then
+/// also carry `#[validate(custom_with_context = "path::to::fn")]`, whose
+/// function instead takes `(&FieldType, &MyCtx)`; this rule only runs through
+/// `validate_with` and is skipped by the plain `validate()` method, since it
+/// has no context to give it. `custom` and `custom_with_context` can be mixed
+/// freely, even on the same field, since which signature a rule expects is
+/// decided per-rule rather than once for the whole struct.
+///
+/// The struct may also carry `#[validate(fail_fast)]`, which stops checking
+/// as soon as the first rule fails — whether that rule is the first of
+/// several on one field or the first on a later field — instead of
+/// collecting every failure. This matters for cheap rejection of large
+/// nested payloads. `Validate::validate_result`, a thin `Result`-returning
+/// wrapper around `validate()`, comes for free via the trait's default
+/// implementation and needs no derive support.
+///
+/// # Constraints
+///
+/// - The macro cannot be derived for unions.
+/// - A `#[validate(nested)]` field's type must implement `Validate`.
+///
+/// # Examples
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
 /// #[derive(Validate)]
 /// struct User {
-///     username: NonEmptyString,
-///     email: NonEmptyString,
-/// }
+///     #[validate(length(min = 3, max = 20))]
+///     username: String,
 ///
+///     #[validate(email)]
+///     email: String,
+/// }
 ///
 /// fn main() {
 ///     let user = User {
-///         username: "".to_string().into(),
-///         email: "invalidemail.com".to_string().into(),
+///         username: "ab".to_string(),
+///         email: "invalidemail.com".to_string(),
 ///     };
 ///
 ///     let errors = user.validate();
@@ -65,69 +911,265 @@ use struct_validation_core::ValidationError;
 ///
 /// **Output:**
 /// ```text
-/// Error in username: must not be empty
-/// Error in email: must not be empty
+/// Error in username: must be between 3 and 20 characters long
+/// Error in email: must be a valid email address
+/// ```
+///
+/// `range`, `length` (with a `message` override), and `must_match` together:
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
+/// #[derive(Validate)]
+/// struct ChangePassword {
+///     #[validate(range(min = 18, max = 120, message = "must be an adult"))]
+///     age: u8,
+///
+///     #[validate(length(min = 8))]
+///     new_password: String,
+///
+///     #[validate(must_match = "new_password")]
+///     confirm_password: String,
+/// }
+///
+/// let form = ChangePassword {
+///     age: 16,
+///     new_password: "short".to_string(),
+///     confirm_password: "different".to_string(),
+/// };
+///
+/// let errors = form.validate();
+/// assert_eq!(errors.len(), 3);
+/// assert_eq!(errors[0].message, "must be an adult");
+/// assert_eq!(errors[1].message, "must be at least 8 characters long");
+/// assert_eq!(errors[2].message, "must match `new_password`");
 /// ```
-#[proc_macro_derive(Validate)]
+///
+/// A `custom` validator (no context needed) and a `custom_with_context`
+/// validator (needs the struct's declared context) can live on the same
+/// field; `custom` runs under both `validate()` and `validate_with`, while
+/// `custom_with_context` only runs once a context is actually supplied:
+///
+/// ```rust
+/// use struct_validation_core::{Validate, ValidationError};
+/// use struct_validation_derive::Validate;
+///
+/// struct MaxLen(usize);
+///
+/// fn not_blank(value: &String) -> Result<(), ValidationError> {
+///     if value.is_empty() {
+///         Err(ValidationError::new("name", "must not be empty"))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// fn within_limit(value: &String, ctx: &MaxLen) -> Result<(), ValidationError> {
+///     if value.len() > ctx.0 {
+///         Err(ValidationError::new("name", "too long"))
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// #[validate(context = "MaxLen")]
+/// struct Profile {
+///     #[validate(custom = "not_blank", custom_with_context = "within_limit")]
+///     name: String,
+/// }
+///
+/// let too_long = Profile { name: "abcdef".to_string() };
+///
+/// // `custom` alone can't see that the name is over the limit...
+/// assert_eq!(too_long.validate().len(), 0);
+/// // ...but `custom_with_context` can, once a context is supplied.
+/// assert_eq!(too_long.validate_with(&MaxLen(3)).len(), 1);
+///
+/// let blank = Profile { name: String::new() };
+///
+/// // `custom` runs regardless of whether a context is ever supplied.
+/// assert_eq!(blank.validate().len(), 1);
+/// ```
+///
+/// `#[validate(fail_fast)]` stops at the very first failing rule, even when
+/// a single field carries more than one rule that would otherwise both fail:
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
+/// #[derive(Validate)]
+/// #[validate(fail_fast)]
+/// struct Signup {
+///     #[validate(length(min = 10), contains = "@")]
+///     handle: String,
+/// }
+///
+/// let signup = Signup { handle: "x".to_string() };
+///
+/// // `length` and `contains` both fail for "x", but fail_fast stops after the first.
+/// assert_eq!(signup.validate().len(), 1);
+/// ```
+///
+/// `fail_fast` also short-circuits a `#[validate(nested)]` collection:
+/// validation of a `Vec<T>`'s elements stops at the first element that
+/// produces an error, instead of visiting every element:
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
+/// #[derive(Validate)]
+/// struct LineItem {
+///     #[validate(length(min = 1))]
+///     sku: String,
+/// }
+///
+/// #[derive(Validate)]
+/// #[validate(fail_fast)]
+/// struct Order {
+///     #[validate(nested)]
+///     items: Vec<LineItem>,
+/// }
+///
+/// let order = Order {
+///     items: vec![
+///         LineItem { sku: String::new() },
+///         LineItem { sku: String::new() },
+///         LineItem { sku: String::new() },
+///     ],
+/// };
+///
+/// // All three items fail, but fail_fast stops at the first.
+/// assert_eq!(order.validate().len(), 1);
+/// ```
+///
+/// `#[validate(nested)]` descends into a `Vec<T>` of nested structs, prefixing
+/// each element's errors with its index; `#[validate(skip)]` documents a field
+/// that is deliberately left unchecked:
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
+/// #[derive(Validate)]
+/// struct LineItem {
+///     #[validate(length(min = 1))]
+///     sku: String,
+/// }
+///
+/// #[derive(Validate)]
+/// struct Order {
+///     #[validate(nested)]
+///     items: Vec<LineItem>,
+///
+///     #[validate(skip)]
+///     internal_notes: String,
+/// }
+///
+/// let order = Order {
+///     items: vec![LineItem { sku: "ABC".to_string() }, LineItem { sku: String::new() }],
+///     internal_notes: String::new(),
+/// };
+///
+/// let errors = order.validate();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].field, "items[1].sku");
+/// ```
+///
+/// `Validate` can also be derived for tuple/newtype structs (fields addressed
+/// by positional index) and for enums (each variant matched independently,
+/// errors prefixed with the variant name):
+///
+/// ```rust
+/// use struct_validation_core::Validate;
+/// use struct_validation_derive::Validate;
+///
+/// #[derive(Validate)]
+/// struct Percentage(#[validate(range(min = 0, max = 100))] i32);
+///
+/// let errors = Percentage(150).validate();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].field, "0");
+///
+/// #[derive(Validate)]
+/// enum Payment {
+///     Free,
+///     Paid {
+///         #[validate(range(min = 1))]
+///         amount: i32,
+///     },
+/// }
+///
+/// let errors = Payment::Paid { amount: 0 }.validate();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].field, "Paid.amount");
+/// assert!(Payment::Free.validate().is_empty());
+/// ```
+#[proc_macro_derive(Validate, attributes(validate))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     // Parse the input token stream as a Rust struct
     let input = parse_macro_input!(input as DeriveInput);
 
-    // Extract the struct name
+    // Extract the struct/enum name
     let struct_name = &input.ident;
 
-    // Ensure the input is a struct with named fields
-    let fields = if let Data::Struct(data) = &input.data {
-        match &data.fields {
-            Fields::Named(fields) => &fields.named,
-            _ => {
-                // Emit a compile error if not a struct with named fields
-                return Error::new_spanned(
-                    struct_name,
-                    "Validate can only be derived for structs with named fields",
-                )
-                .to_compile_error()
-                .into();
-            }
-        }
-    } else {
-        // Emit a compile error if not a struct
-        return Error::new_spanned(
-            struct_name,
-            "Validate can only be derived for structs",
-        )
-        .to_compile_error()
-        .into();
+    // A struct-level `#[validate(context = "CtxType")]` attribute requests a
+    // second `validate_with` entry point that threads a shared context through
+    // to `custom_with_context` validator functions.
+    let context_ty = match parse_struct_context(&input.attrs) {
+        Ok(ty) => ty,
+        Err(err) => return err.to_compile_error().into(),
     };
+    let custom_ctx_for_validate = CustomCtx { has_context: context_ty.is_some(), in_scope: false };
 
-    // Generate validation code for each field, ensuring each implements Validate
-    let validator_iters = fields.iter().map(|field| {
-        // Extract the field name as an identifier
-        let field_name = &field.ident;
-        // Convert the field name to a string for error prefixing
-        let field_name_str = field_name.as_ref().unwrap().to_string();
+    // A struct-level `#[validate(fail_fast)]` attribute stops checking a
+    // struct/variant's fields as soon as the first error is recorded.
+    let fail_fast = match parse_struct_fail_fast(&input.attrs) {
+        Ok(fail_fast) => fail_fast,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-        quote! {
-            self.#field_name.validate()
-                .into_iter()
-                .map(|mut e| { e.add_prefix(#field_name_str); e })
-        }
-    });
+    let body = match build_validate_blocks(&input.data, struct_name, custom_ctx_for_validate, fail_fast) {
+        Ok(body) => body,
+        Err(err) => return err.to_compile_error().into(),
+    };
 
-    // Chain all iterators or use an empty iterator if no fields are present
-    let stream = validator_iters.reduce(|acc, stream| {
-        quote! {
-            #acc.chain(#stream)
+    let validate_with_method = match &context_ty {
+        Some(ctx_ty) => {
+            let with_body = match build_validate_blocks(&input.data, struct_name, CustomCtx { has_context: true, in_scope: true }, fail_fast) {
+                Ok(body) => body,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            quote! {
+                impl #struct_name {
+                    /// Like [`Validate::validate`], but threads `ctx` through to every
+                    /// `#[validate(custom_with_context = "...")]` function on this struct
+                    /// so they can consult external state while validating.
+                    pub fn validate_with(&self, ctx: &#ctx_ty) -> Vec<struct_validation_core::ValidationError> {
+                        let mut errors = Vec::new();
+                        #with_body
+                        errors
+                    }
+                }
+            }
         }
-    }).unwrap_or_else(|| quote! { std::iter::empty() });
+        None => quote! {},
+    };
 
-    // Generate the final implementation of Validate for the struct
+    // Generate the final implementation of Validate for the struct/enum
     let expanded = quote! {
         impl Validate for #struct_name {
             fn validate(&self) -> Vec<struct_validation_core::ValidationError> {
-                #stream.collect()
+                let mut errors = Vec::new();
+                #body
+                errors
             }
         }
+
+        #validate_with_method
     };
 
     // Convert the generated code into a TokenStream and return it